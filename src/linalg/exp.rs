@@ -3,14 +3,91 @@
 use crate::{
     base::{
         allocator::Allocator,
-        dimension::{Dim, DimMin, DimMinimum, U1},
+        dimension::{Dim, DimAdd, DimMin, DimMinimum, DimSum, U1},
         storage::Storage,
         DefaultAllocator,
     },
-    convert, try_convert, ComplexField, MatrixN, RealField,
+    convert, try_convert, ComplexField, MatrixMN, MatrixN, RealField,
 };
 
 use crate::num::Zero;
+use std::fmt;
+
+/// Errors that can occur while computing the principal matrix logarithm with
+/// [`MatrixN::try_log`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogError {
+    /// The inverse scaling-and-squaring iteration did not converge. This typically means
+    /// `self` is singular, defective, or has an eigenvalue on the (closed) negative real
+    /// axis, where the principal logarithm is not defined.
+    NotConverged,
+}
+
+impl fmt::Display for LogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogError::NotConverged => write!(
+                f,
+                "the principal matrix logarithm did not converge; self may be singular or \
+                 have an eigenvalue on the negative real axis"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LogError {}
+
+/// Errors that can occur while computing the principal matrix square root with
+/// [`MatrixN::try_sqrt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqrtError {
+    /// The Denman-Beavers iteration hit a singular iterate or did not converge within its
+    /// iteration budget. This
+    /// typically means `self` is singular, defective, or otherwise has no square root
+    /// reachable by the iteration.
+    NotConverged,
+}
+
+impl fmt::Display for SqrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqrtError::NotConverged => write!(
+                f,
+                "the Denman-Beavers iteration did not converge; self may be singular or \
+                 defective"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SqrtError {}
+
+/// Errors that can occur while computing the matrix exponential with
+/// [`MatrixN::try_exp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpError {
+    /// The linear system solved to evaluate the Padé approximant, `(v - u) X = v + u`, was
+    /// singular.
+    SingularPadeDenominator,
+    /// The scaling-and-squaring exponent `s` could not be computed, e.g. because `self`'s
+    /// norm over- or underflows the estimates `ell` relies on.
+    ScalingExponentOverflow,
+}
+
+impl fmt::Display for ExpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpError::SingularPadeDenominator => {
+                write!(f, "the Padé approximant denominator is singular")
+            }
+            ExpError::ScalingExponentOverflow => {
+                write!(f, "the scaling-and-squaring exponent could not be computed")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExpError {}
 
 // https://github.com/scipy/scipy/blob/c1372d8aa90a73d8a52f135529293ff4edb98fc8/scipy/sparse/linalg/matfuncs.py
 struct ExpmPadeHelper<N, D>
@@ -334,11 +411,11 @@ where
     }
 }
 
-fn factorial(n: u128) -> u128 {
-    if n == 1 {
-        return 1;
-    }
-    n * factorial(n - 1)
+/// `ln(n!)`, computed as a sum of logarithms instead of an integer product so that it
+/// never overflows, even for the large `n` that show up as `2m` and `2m + 1` for bigger
+/// Padé/Taylor orders `m`.
+fn ln_factorial(n: u64) -> f64 {
+    (2..=n).fold(0.0, |acc, i| acc + (i as f64).ln())
 }
 
 /// Compute the 1-norm of a non-negative integer power of a non-negative matrix.
@@ -359,7 +436,7 @@ where
     v.max()
 }
 
-fn ell<N, D>(a: &MatrixN<N, D>, m: u64) -> u64
+fn ell<N, D>(a: &MatrixN<N, D>, m: u64) -> Result<u64, ExpError>
 where
     N: ComplexField,
     D: Dim,
@@ -368,33 +445,40 @@ where
         + Allocator<N::RealField, D>
         + Allocator<N::RealField, D, D>,
 {
-    // 2m choose m = (2m)!/(m! * (2m-m)!)
-
     let a_abs = a.map(|x| x.abs());
 
     let a_abs_onenorm = onenorm_matrix_power_nonm(&a_abs, 2 * m + 1);
 
     if a_abs_onenorm == <N as ComplexField>::RealField::zero() {
-        return 0;
+        return Ok(0);
     }
 
-    let choose_2m_m =
-        factorial(2 * m as u128) / (factorial(m as u128) * factorial(2 * m as u128 - m as u128));
-    let abs_c_recip = choose_2m_m * factorial(2 * m as u128 + 1);
+    // ln(2m choose m * (2m + 1)!), evaluated as a sum of logarithms via `ln_factorial` so
+    // it cannot overflow the way the equivalent integer factorial computation would for
+    // larger `m`.
+    let ln_abs_c_recip = ln_factorial(2 * m + 1) + ln_factorial(2 * m) - 2.0 * ln_factorial(m);
+
     let alpha = a_abs_onenorm / one_norm(a);
-    let alpha: f64 = try_convert(alpha).unwrap() / abs_c_recip as f64;
+    let alpha: f64 = try_convert(alpha).ok_or(ExpError::ScalingExponentOverflow)?;
+    if !alpha.is_finite() || alpha <= 0.0 {
+        return Err(ExpError::ScalingExponentOverflow);
+    }
 
-    let u = 2_f64.powf(-53.0);
-    let log2_alpha_div_u = (alpha / u).log2();
+    let log2_u = -53.0;
+    let log2_alpha_div_u = (alpha.ln() - ln_abs_c_recip) / std::f64::consts::LN_2 - log2_u;
     let value = (log2_alpha_div_u / (2.0 * m as f64)).ceil();
+
     if value > 0.0 {
-        value as u64
+        if !value.is_finite() || value > u64::MAX as f64 {
+            return Err(ExpError::ScalingExponentOverflow);
+        }
+        Ok(value as u64)
     } else {
-        0
+        Ok(0)
     }
 }
 
-fn solve_p_q<N, D>(u: MatrixN<N, D>, v: MatrixN<N, D>) -> MatrixN<N, D>
+fn try_solve_p_q<N, D>(u: MatrixN<N, D>, v: MatrixN<N, D>) -> Result<MatrixN<N, D>, ExpError>
 where
     N: ComplexField,
     D: DimMin<D, Output = D>,
@@ -403,7 +487,197 @@ where
     let p = &u + &v;
     let q = &v - &u;
 
-    q.lu().solve(&p).unwrap()
+    q.lu().solve(&p).ok_or(ExpError::SingularPadeDenominator)
+}
+
+// Table 3.1 of Al-Mohy and Higham, "Computing the Action of the Matrix Exponential,
+// with an Application to Exponential Integrators", giving `theta_m`, the largest scaled
+// norm for which the degree-m truncated Taylor series is accurate to working precision.
+const THETA: [(u64, f64); 35] = [
+    (1, 2.29e-16),
+    (2, 2.58e-8),
+    (3, 1.39e-5),
+    (4, 3.40e-4),
+    (5, 2.40e-3),
+    (6, 9.07e-3),
+    (7, 2.38e-2),
+    (8, 5.00e-2),
+    (9, 8.96e-2),
+    (10, 1.44e-1),
+    (11, 2.14e-1),
+    (12, 3.00e-1),
+    (13, 4.00e-1),
+    (14, 5.14e-1),
+    (15, 6.41e-1),
+    (16, 7.81e-1),
+    (17, 9.31e-1),
+    (18, 1.09),
+    (19, 1.26),
+    (20, 1.44),
+    (21, 1.62),
+    (22, 1.82),
+    (23, 2.01),
+    (24, 2.22),
+    (25, 2.43),
+    (26, 2.64),
+    (27, 2.86),
+    (28, 3.08),
+    (29, 3.31),
+    (30, 3.54),
+    (35, 4.7),
+    (40, 6.0),
+    (45, 7.2),
+    (50, 8.5),
+    (55, 9.9),
+];
+
+/// Estimates `d_p = ||A^p||^(1/p)` for the non-negative matrix `a_abs` (element-wise
+/// absolute value of `A`), reusing `onenorm_matrix_power_nonm`.
+fn d_p<N, D>(a_abs: &MatrixN<N, D>, p: u64) -> f64
+where
+    N: RealField,
+    D: Dim,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+{
+    let d = onenorm_matrix_power_nonm(a_abs, p).powf(convert(1.0 / p as f64));
+    try_convert(d).unwrap()
+}
+
+/// Picks the Taylor truncation order `m` and number of substeps `s` that minimize the
+/// cost `s * m` of `exp_multiply`, subject to `s * theta_m >= |t| * max_p d_p`, where
+/// `max_p d_p` is taken over `p` in `{m, m + 1}` as an estimate of the worst-case growth
+/// rate of `||A^p||^(1/p)` relevant to truncation order `m`.
+fn select_taylor_params<N, D>(a: &MatrixN<N, D>, t_abs: f64) -> (u64, u64)
+where
+    N: ComplexField,
+    D: Dim,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D, D> + Allocator<N::RealField, D>,
+{
+    let a_abs = a.map(|x| x.abs());
+
+    let mut best: Option<(u64, u64)> = None;
+
+    for &(m, theta_m) in &THETA {
+        let max_d = d_p(&a_abs, m).max(d_p(&a_abs, m + 1));
+
+        // A non-finite estimate (e.g. from a huge shifted norm overflowing `f64` while
+        // raised to the `m`-th power) can't be compared or costed sensibly; skip it
+        // rather than let it saturate `s` and overflow `cost` below.
+        if !max_d.is_finite() {
+            continue;
+        }
+
+        if max_d == 0.0 {
+            return (m, 1);
+        }
+
+        let s = (t_abs * max_d / theta_m).ceil().max(1.0);
+        if !s.is_finite() {
+            continue;
+        }
+
+        // Compare costs as `f64` so a large `s` can never overflow a `u64` multiplication.
+        let cost = s * m as f64;
+
+        let is_better = match best {
+            Some((best_m, best_s)) => cost < best_s as f64 * best_m as f64,
+            None => true,
+        };
+
+        if is_better {
+            best = Some((m, s.min(u64::MAX as f64) as u64));
+        }
+    }
+
+    best.unwrap_or((13, 1))
+}
+
+/// Computes `sqrt(a)` with the Denman-Beavers iteration `Y_0 = A, Z_0 = I`,
+/// `Y_{k+1} = (Y_k + Z_k^{-1}) / 2`, `Z_{k+1} = (Z_k + Y_k^{-1}) / 2`, which converges
+/// `Y_k -> sqrt(A)`. Returns `None` if an iterate becomes singular or the iteration fails
+/// to converge within a fixed number of steps.
+fn denman_beavers_sqrt<N, D>(a: &MatrixN<N, D>) -> Option<MatrixN<N, D>>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), DimMinimum<D, D>>,
+{
+    const MAX_ITER: usize = 100;
+
+    let (nrows, ncols) = a.data.shape();
+    let ident = MatrixN::<N, D>::identity_generic(nrows, ncols);
+    let tol = convert::<f64, N::RealField>(1.0e-12);
+
+    let mut y = a.clone();
+    let mut z = ident.clone();
+
+    for _ in 0..MAX_ITER {
+        let y_inv = y.clone().lu().solve(&ident)?;
+        let z_inv = z.clone().lu().solve(&ident)?;
+
+        let y_next = (&y + &z_inv) * convert::<f64, N>(0.5);
+        let z_next = (&z + &y_inv) * convert::<f64, N>(0.5);
+
+        let scale = one_norm(&y_next);
+        let diff = one_norm(&(&y_next - &y));
+
+        y = y_next;
+        z = z_next;
+
+        if scale == N::RealField::zero() || diff / scale < tol {
+            return Some(y);
+        }
+    }
+
+    None
+}
+
+/// Evaluates `log(I + X)` with an 8-point Gauss-Legendre quadrature of the integral
+/// representation `log(I + X) = int_0^1 X (I + s X)^-1 ds`, which is equivalent to the
+/// diagonal Padé approximant of `log(1 + x)`. Each quadrature node reuses the
+/// "build a linear system in `X`, then solve it" pattern of `try_solve_p_q`.
+fn log_pade<N, D>(x: &MatrixN<N, D>) -> Option<MatrixN<N, D>>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), DimMinimum<D, D>>,
+{
+    const NODES: [f64; 8] = [
+        0.019855071751231884,
+        0.101666761293186630,
+        0.237233795041835510,
+        0.408282678752174930,
+        0.591717321247825070,
+        0.762766204958164490,
+        0.898333238706813370,
+        0.980144928248768120,
+    ];
+    const WEIGHTS: [f64; 8] = [
+        0.050614268145188130,
+        0.111190517226687230,
+        0.156853322938943960,
+        0.181341891689181100,
+        0.181341891689181100,
+        0.156853322938943960,
+        0.111190517226687230,
+        0.050614268145188130,
+    ];
+
+    let (nrows, ncols) = x.data.shape();
+    let ident = MatrixN::<N, D>::identity_generic(nrows, ncols);
+
+    let mut l = MatrixN::<N, D>::zeros_generic(nrows, ncols);
+    for (&node, &weight) in NODES.iter().zip(WEIGHTS.iter()) {
+        let node = convert::<f64, N>(node);
+        let weight = convert::<f64, N>(weight);
+
+        let denom = &ident + &(x * node);
+        let y = denom.lu().solve(x)?;
+
+        l = l + y * weight;
+    }
+
+    Some(l)
 }
 
 fn one_norm<N, D>(m: &MatrixN<N, D>) -> N::RealField
@@ -434,35 +708,36 @@ where
         + Allocator<N::RealField, D>
         + Allocator<N::RealField, D, D>,
 {
-    /// Computes exponential of this matrix
-    pub fn exp(&self) -> Self {
+    /// Computes the exponential of this matrix, returning an error rather than panicking
+    /// when the Padé denominator is singular or the scaling exponent cannot be computed.
+    pub fn try_exp(&self) -> Result<Self, ExpError> {
         // Simple case
         if self.nrows() == 1 {
-            return self.map(|v| v.exp());
+            return Ok(self.map(|v| v.exp()));
         }
 
         let mut h = ExpmPadeHelper::new(self.clone(), true);
 
         let eta_1 = N::RealField::max(h.d4_loose(), h.d6_loose());
-        if eta_1 < convert(1.495585217958292e-002) && ell(&h.a, 3) == 0 {
+        if eta_1 < convert(1.495585217958292e-002) && ell(&h.a, 3)? == 0 {
             let (u, v) = h.pade3();
-            return solve_p_q(u, v);
+            return try_solve_p_q(u, v);
         }
 
         let eta_2 = N::RealField::max(h.d4_tight(), h.d6_loose());
-        if eta_2 < convert(2.539398330063230e-001) && ell(&h.a, 5) == 0 {
+        if eta_2 < convert(2.539398330063230e-001) && ell(&h.a, 5)? == 0 {
             let (u, v) = h.pade5();
-            return solve_p_q(u, v);
+            return try_solve_p_q(u, v);
         }
 
         let eta_3 = N::RealField::max(h.d6_tight(), h.d8_loose());
-        if eta_3 < convert(9.504178996162932e-001) && ell(&h.a, 7) == 0 {
+        if eta_3 < convert(9.504178996162932e-001) && ell(&h.a, 7)? == 0 {
             let (u, v) = h.pade7();
-            return solve_p_q(u, v);
+            return try_solve_p_q(u, v);
         }
-        if eta_3 < convert(2.097847961257068e+000) && ell(&h.a, 9) == 0 {
+        if eta_3 < convert(2.097847961257068e+000) && ell(&h.a, 9)? == 0 {
             let (u, v) = h.pade9();
-            return solve_p_q(u, v);
+            return try_solve_p_q(u, v);
         }
 
         let eta_4 = N::RealField::max(h.d8_loose(), h.d10_loose());
@@ -476,20 +751,205 @@ where
 
             if l2 < 0.0 {
                 0
+            } else if !l2.is_finite() || l2 > u64::MAX as f64 {
+                return Err(ExpError::ScalingExponentOverflow);
             } else {
                 l2 as u64
             }
         };
 
-        s += ell(&(&h.a * convert::<f64, N>(2.0_f64.powf(-(s as f64)))), 13);
+        s += ell(&(&h.a * convert::<f64, N>(2.0_f64.powf(-(s as f64)))), 13)?;
 
         let (u, v) = h.pade13_scaled(s);
-        let mut x = solve_p_q(u, v);
+        let mut x = try_solve_p_q(u, v)?;
 
         for _ in 0..s {
             x = &x * &x;
         }
-        x
+        Ok(x)
+    }
+
+    /// Computes the exponential of this matrix.
+    ///
+    /// # Panics
+    /// Panics if the Padé denominator is singular or the scaling exponent cannot be
+    /// computed. See [`Self::try_exp`] for a non-panicking variant.
+    pub fn exp(&self) -> Self {
+        self.try_exp().unwrap()
+    }
+
+    /// Computes the action `exp(t * self) * b` of the matrix exponential on `b`, without
+    /// forming the dense `exp(t * self)`.
+    ///
+    /// This is much cheaper than `self.exp() * b` when `self` is large, since it follows
+    /// the scaled Taylor series method of Al-Mohy and Higham ("Computing the Action of the
+    /// Matrix Exponential, with an Application to Exponential Integrators") instead of
+    /// materializing the full matrix exponential.
+    pub fn exp_multiply<D2>(&self, t: N, b: &MatrixMN<N, D, D2>) -> MatrixMN<N, D, D2>
+    where
+        D2: Dim,
+        DefaultAllocator: Allocator<N, D, D2>,
+    {
+        let n = self.nrows();
+        let mu = self.trace() / convert(n as f64);
+
+        let ident = MatrixN::<N, D>::identity_generic(self.data.shape().0, self.data.shape().1);
+        let shifted = self - &ident * mu;
+
+        let t_abs: f64 = try_convert(t.abs()).unwrap();
+        let (m, s) = select_taylor_params(&shifted, t_abs);
+
+        let mut b0 = b.clone();
+        let mut f = b0.clone();
+
+        let tol: N::RealField = convert(2.0_f64.powi(-53));
+
+        for _ in 0..s {
+            for k in 1..=m {
+                let coeff = convert::<f64, N>(1.0 / (s * k) as f64) * t;
+                b0 = (&shifted * &b0) * coeff;
+                f = &f + &b0;
+
+                if k >= 2 {
+                    let b0_norm = b0.iter().fold(N::RealField::zero(), |acc, v| acc.max(v.abs()));
+                    let f_norm = f.iter().fold(N::RealField::zero(), |acc, v| acc.max(v.abs()));
+                    if b0_norm <= tol * f_norm {
+                        break;
+                    }
+                }
+            }
+            b0 = f.clone();
+        }
+
+        f * (t * mu).exp()
+    }
+
+    /// Computes the Fréchet derivative `L(self, e)` of the matrix exponential at `self` in
+    /// the direction `e`, returning `(exp(self), L(self, e))`.
+    ///
+    /// This relies on the block-matrix identity
+    /// `exp([[A, E], [0, A]]) = [[exp(A), L(A, E)], [0, exp(A)]]`: it builds the doubled
+    /// `2n x 2n` matrix `[[A, E], [0, A]]` (its dimension tied to `D` via `DimSum<D, D>`, so
+    /// it is always exactly twice `self`'s), exponentiates it with the scaling-and-squaring
+    /// `exp` already implemented on this type, and slices the two blocks back out.
+    pub fn exp_frechet(&self, e: &MatrixN<N, D>) -> (Self, Self)
+    where
+        D: DimAdd<D>,
+        DimSum<D, D>: DimMin<DimSum<D, D>, Output = DimSum<D, D>>,
+        DefaultAllocator: Allocator<N, DimSum<D, D>, DimSum<D, D>>
+            + Allocator<(usize, usize), DimMinimum<DimSum<D, D>, DimSum<D, D>>>
+            + Allocator<N, DimSum<D, D>>
+            + Allocator<N::RealField, DimSum<D, D>>
+            + Allocator<N::RealField, DimSum<D, D>, DimSum<D, D>>,
+    {
+        let n = self.nrows();
+        let shape = self.data.shape();
+        let doubled = shape.0.add(shape.0);
+
+        let mut m = MatrixN::<N, DimSum<D, D>>::zeros_generic(doubled, doubled);
+        for i in 0..n {
+            for j in 0..n {
+                m[(i, j)] = self[(i, j)].clone();
+                m[(i, n + j)] = e[(i, j)].clone();
+                m[(n + i, n + j)] = self[(i, j)].clone();
+            }
+        }
+
+        let exp_m = m.exp();
+
+        let mut exp_a = Self::zeros_generic(shape.0, shape.1);
+        let mut l = Self::zeros_generic(shape.0, shape.1);
+        for i in 0..n {
+            for j in 0..n {
+                exp_a[(i, j)] = exp_m[(i, j)].clone();
+                l[(i, j)] = exp_m[(i, n + j)].clone();
+            }
+        }
+
+        (exp_a, l)
+    }
+
+    /// The 1-norm condition number of `exp` at `self` with respect to the direction `e`,
+    /// computed from the Fréchet derivative as `||self|| * ||L(self, e)|| / ||exp(self)||`.
+    pub fn exp_cond(&self, e: &MatrixN<N, D>) -> N::RealField
+    where
+        D: DimAdd<D>,
+        DimSum<D, D>: DimMin<DimSum<D, D>, Output = DimSum<D, D>>,
+        DefaultAllocator: Allocator<N, DimSum<D, D>, DimSum<D, D>>
+            + Allocator<(usize, usize), DimMinimum<DimSum<D, D>, DimSum<D, D>>>
+            + Allocator<N, DimSum<D, D>>
+            + Allocator<N::RealField, DimSum<D, D>>
+            + Allocator<N::RealField, DimSum<D, D>, DimSum<D, D>>,
+    {
+        let (exp_a, l) = self.exp_frechet(e);
+        one_norm(self) * one_norm(&l) / one_norm(&exp_a)
+    }
+
+    /// Computes the principal matrix logarithm, i.e. the inverse of [`Self::exp`] such
+    /// that `self.try_log().unwrap().exp() == self` (up to numerical error), using inverse
+    /// scaling-and-squaring with a diagonal Padé approximant.
+    ///
+    /// Returns `LogError::NotConverged` if `self` has an eigenvalue on the (closed)
+    /// negative real axis, where the principal logarithm is undefined, or if the
+    /// square-root iteration otherwise fails to converge.
+    pub fn try_log(&self) -> Result<Self, LogError> {
+        if self.nrows() == 1 {
+            let v = self[(0, 0)].clone();
+            if v.clone().imaginary() == N::RealField::zero()
+                && v.clone().real() <= N::RealField::zero()
+            {
+                return Err(LogError::NotConverged);
+            }
+            return Ok(self.map(|v| v.ln()));
+        }
+
+        let (nrows, ncols) = self.data.shape();
+        let ident = MatrixN::<N, D>::identity_generic(nrows, ncols);
+        let theta = convert::<f64, N::RealField>(0.25);
+
+        const MAX_SQUARE_ROOTS: u32 = 32;
+
+        let mut a = self.clone();
+        let mut k = 0u32;
+
+        while one_norm(&(&a - &ident)) >= theta {
+            if k >= MAX_SQUARE_ROOTS {
+                return Err(LogError::NotConverged);
+            }
+            a = denman_beavers_sqrt(&a).ok_or(LogError::NotConverged)?;
+            k += 1;
+        }
+
+        let x = &a - &ident;
+        let l = log_pade(&x).ok_or(LogError::NotConverged)?;
+
+        Ok(l * convert::<f64, N>(2.0_f64.powi(k as i32)))
+    }
+
+    /// Computes the principal matrix logarithm.
+    ///
+    /// # Panics
+    /// Panics if the logarithm is not defined for `self`, or if the underlying iteration
+    /// fails to converge. See [`Self::try_log`] for a non-panicking variant.
+    pub fn log(&self) -> Self {
+        self.try_log().unwrap()
+    }
+
+    /// Computes the principal square root of `self` with the Denman-Beavers iteration.
+    ///
+    /// Returns `SqrtError::NotConverged` if the iteration does not converge, e.g. because
+    /// `self` is singular or defective.
+    pub fn try_sqrt(&self) -> Result<Self, SqrtError> {
+        denman_beavers_sqrt(self).ok_or(SqrtError::NotConverged)
+    }
+
+    /// Computes the principal square root of `self`.
+    ///
+    /// # Panics
+    /// Panics if the underlying Denman-Beavers iteration fails to converge. See
+    /// [`Self::try_sqrt`] for a non-panicking variant.
+    pub fn sqrt(&self) -> Self {
+        self.try_sqrt().unwrap()
     }
 }
 
@@ -502,4 +962,93 @@ mod tests {
 
         assert_eq!(super::one_norm(&m), 19.0);
     }
+
+    #[test]
+    fn exp_multiply_matches_dense_exp() {
+        use crate::{Matrix3, Vector3};
+
+        let a = Matrix3::new(0.1, 0.2, 0.0, 0.3, -0.1, 0.2, 0.0, 0.1, 0.4);
+        let b = Vector3::new(1.0, -2.0, 0.5);
+        let t = 0.75;
+
+        let action = a.exp_multiply(t, &b);
+        let dense = (a * t).exp() * b;
+
+        let max_err = (action - dense).iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        assert!(max_err < 1.0e-8);
+    }
+
+    #[test]
+    fn exp_frechet_matches_finite_differences() {
+        use crate::Matrix2;
+
+        let a = Matrix2::new(0.1, 0.2, 0.3, 0.4);
+        let e = Matrix2::new(0.5, -0.1, 0.2, 0.3);
+
+        let (exp_a, l) = a.exp_frechet(&e);
+
+        let exp_a_err = (exp_a - a.exp()).iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        assert!(exp_a_err < 1.0e-10);
+
+        let h = 1.0e-6;
+        let fd = ((a + e * h).exp() - (a - e * h).exp()) * (0.5 / h);
+
+        let max_err = (l - fd).iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        assert!(max_err < 1.0e-4);
+    }
+
+    #[test]
+    fn try_exp_reports_error_for_non_finite_input() {
+        use crate::Matrix3;
+
+        let a = Matrix3::new(f64::NAN, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+
+        assert!(matches!(
+            a.try_exp(),
+            Err(super::ExpError::ScalingExponentOverflow)
+        ));
+    }
+
+    #[test]
+    fn log_is_inverse_of_exp() {
+        use crate::Matrix2;
+
+        let a = Matrix2::new(0.1, 0.2, -0.3, 0.4);
+        let roundtrip = a.exp().try_log().unwrap();
+
+        let max_err = (roundtrip - a).iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        assert!(max_err < 1.0e-8);
+    }
+
+    #[test]
+    fn try_log_reports_error_for_negative_real_eigenvalue() {
+        use crate::Matrix2;
+
+        // The principal logarithm is undefined for a negative real eigenvalue.
+        let a = Matrix2::new(-1.0, 0.0, 0.0, 2.0);
+
+        assert_eq!(a.try_log(), Err(super::LogError::NotConverged));
+    }
+
+    #[test]
+    fn sqrt_squares_back_to_input() {
+        use crate::Matrix2;
+
+        let a = Matrix2::new(4.0, 1.0, 2.0, 3.0);
+        let sqrt_a = a.sqrt();
+
+        let max_err = (sqrt_a * sqrt_a - a).iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        assert!(max_err < 1.0e-10);
+    }
+
+    #[test]
+    fn try_sqrt_reports_error_for_negative_real_eigenvalue() {
+        use crate::Matrix2;
+
+        // Real Denman-Beavers iteration has no real square root for a negative real
+        // eigenvalue and hits a singular intermediate instead of converging.
+        let a = Matrix2::new(-1.0, 0.0, 0.0, 2.0);
+
+        assert_eq!(a.try_sqrt(), Err(super::SqrtError::NotConverged));
+    }
 }